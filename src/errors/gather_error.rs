@@ -2,7 +2,11 @@
 pub enum GatherError {
     CmdError(fantoccini::error::CmdError),
     NewSessionError(fantoccini::error::NewSessionError),
-    SessionDropped()
+    SessionDropped(),
+    Export(epub_builder::Error),
+    Io(std::io::Error),
+    Http(reqwest::Error),
+    Timeout()
 }
 
 impl From<fantoccini::error::CmdError> for GatherError {
@@ -16,3 +20,21 @@ impl From<fantoccini::error::NewSessionError> for GatherError {
         GatherError::NewSessionError(err)
     }
 }
+
+impl From<epub_builder::Error> for GatherError {
+    fn from(err: epub_builder::Error) -> Self {
+        GatherError::Export(err)
+    }
+}
+
+impl From<std::io::Error> for GatherError {
+    fn from(err: std::io::Error) -> Self {
+        GatherError::Io(err)
+    }
+}
+
+impl From<reqwest::Error> for GatherError {
+    fn from(err: reqwest::Error) -> Self {
+        GatherError::Http(err)
+    }
+}
@@ -1,19 +1,27 @@
+use base64::Engine as _;
 use errors::gather_error::GatherError;
+use futures::stream::FuturesUnordered;
 use futures::{stream, StreamExt};
 use html2md::parse_html;
 
 use models::{
+    cache::Cache,
     html_cleaner::{CleanerConfig, HtmlCleaner},
     news_article::NewsArticle,
+    news_engine::{Engine, NewsEngine},
     news_scraper::NewsScraper,
 };
 
 use regex::Regex;
 
 mod models {
+    pub mod cache;
+    pub mod exporter;
     pub mod html_cleaner;
     pub mod news_article;
+    pub mod news_engine;
     pub mod news_scraper;
+    pub mod readability;
 }
 
 mod errors {
@@ -28,7 +36,7 @@ mod errors {
 ///
 /// # Returns
 /// * `Option<NewsArticle>` - The parsed NewsArticle
-async fn extract_url_headline(text: &str) -> Option<NewsArticle> {
+pub(crate) async fn extract_url_headline(text: &str) -> Option<NewsArticle> {
     let url_regex = Regex::new(r"\[.*?\]\((.*?)\)").unwrap();
     let headline_regex = Regex::new(r"#### (.*?) ####").unwrap();
 
@@ -46,9 +54,13 @@ async fn extract_url_headline(text: &str) -> Option<NewsArticle> {
 
     match (first_url, headline) {
         (Some(url), Some(headline)) => {
-            let full_url = format!("{}{}", url_prefix, &url[1..]);
+            let redirect_url = format!("{}{}", url_prefix, &url[1..]);
+            let resolved_url = google_token(&url)
+                .and_then(resolve_google_url)
+                .unwrap_or(redirect_url);
+
             Some(NewsArticle {
-                url: full_url,
+                url: resolved_url,
                 headline,
             })
         }
@@ -56,6 +68,79 @@ async fn extract_url_headline(text: &str) -> Option<NewsArticle> {
     }
 }
 
+/// # Purpose
+/// Parses a single markdown search result down to its URL and link-text
+/// headline, for engines (Bing, DuckDuckGo) whose result cards don't wrap
+/// their headline in Google News' `#### ... ####` markup - the link text
+/// itself is the headline.
+///
+/// # Parameters
+/// * `text` - The markdown text for a single search result
+///
+/// # Returns
+/// * `Option<NewsArticle>` - The parsed NewsArticle
+pub(crate) fn extract_link_headline(text: &str) -> Option<NewsArticle> {
+    let link_regex = Regex::new(r"\[([^\]]+)\]\(([^)]+)\)").unwrap();
+
+    link_regex.captures_iter(text).find_map(|cap| {
+        let headline = cap.get(1)?.as_str().trim();
+        let url = cap.get(2)?.as_str().trim();
+
+        if headline.is_empty() || url.is_empty() {
+            return None;
+        }
+
+        Some(NewsArticle {
+            url: url.to_string(),
+            headline: headline.to_string(),
+        })
+    })
+}
+
+/// # Purpose
+/// Pulls the base64url-encoded `CBMi...` token out of a Google News article
+/// path such as `./articles/CBMiRW...?hl=en-US`.
+///
+/// # Parameters
+/// * `path` - The relative article path found in the gathered markdown
+///
+/// # Returns
+/// * `Option<&str>` - The encoded token, without the `./articles/` prefix or
+///   trailing query string
+fn google_token(path: &str) -> Option<&str> {
+    let token = path.strip_prefix("./articles/")?;
+    Some(token.split('?').next().unwrap_or(token))
+}
+
+/// # Purpose
+/// Decodes a Google News `CBMi...` redirect token into the real publisher
+/// URL it points to. The token is a base64url-encoded protobuf message; once
+/// decoded, the destination URL is embedded as a length-prefixed string.
+///
+/// # Parameters
+/// * `token` - The encoded token, as returned by `google_token`
+///
+/// # Returns
+/// * `Option<String>` - The decoded publisher URL, or `None` if the token
+///   could not be decoded or no embedded URL was found
+fn resolve_google_url(token: &str) -> Option<String> {
+    let decoded = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(token)
+        .ok()?;
+
+    let url_start = decoded.windows(4).position(|window| window == b"http")?;
+    let len_byte = *decoded.get(url_start.checked_sub(1)?)? as usize;
+    let url_end = (url_start + len_byte).min(decoded.len());
+
+    let url = String::from_utf8(decoded[url_start..url_end].to_vec()).ok()?;
+
+    if url.starts_with("http://") || url.starts_with("https://") {
+        Some(url)
+    } else {
+        None
+    }
+}
+
 /// # Purpose
 /// Reduces a vector of strings (articles) into a single String
 ///
@@ -86,7 +171,7 @@ async fn fold_articles(articles: &Vec<String>) -> String {
 ///
 /// # Returns
 /// * `Vec<String>` - A vector of the cleaned HTML
-async fn clean_html(
+pub(crate) async fn clean_html(
     elements: &Vec<String>,
     html_cleaner_config: &CleanerConfig,
     to_markdown: bool,
@@ -119,7 +204,7 @@ async fn clean_html(
 ///
 /// # Returns
 /// * `Result<Vec<Element>, GatherError>` - The article's elements
-async fn gather_article_elements(url: &str) -> Result<Vec<String>, GatherError> {
+pub(crate) async fn gather_article_elements(url: &str) -> Result<Vec<String>, GatherError> {
     let mut scraper = NewsScraper::new().await?;
     let elements = scraper.get_elements(&url).await?;
     let _ = scraper.close().await;
@@ -127,22 +212,36 @@ async fn gather_article_elements(url: &str) -> Result<Vec<String>, GatherError>
 }
 
 /// # Purpose
-/// Grabs an article from a URL
+/// Grabs an article from a URL, reusing a cached copy of the cleaned
+/// markdown when one exists and hasn't expired.
 ///
 /// # Parameters
 /// * `url` - The URL of the article to be parsed
 /// * `html_cleaner_config` - The configuration for the HTML cleaner
+/// * `cache` - The on-disk cache to check before scraping, and store into after
 ///
 /// # Returns
 /// * `Result<String, GatherError>` - The article text in Markdown
 pub async fn gather_article(
     url: &str,
     html_cleaner_config: &CleanerConfig,
+    cache: &Cache,
 ) -> Result<String, GatherError> {
+    if let Some(cached) = cache.get(url, html_cleaner_config) {
+        return Ok(cached);
+    }
+
+    // Every cache miss is a cheap opportunity to prune expired entries before
+    // writing the freshly-scraped one, so eviction happens without the
+    // caller having to remember to run it.
+    let _ = cache.evict_expired();
+
     let elements = gather_article_elements(&url).await?;
     let parsed_text = clean_html(&elements, &html_cleaner_config, true).await;
     let folded_text = fold_articles(&parsed_text).await;
 
+    cache.put(url, html_cleaner_config, &folded_text)?;
+
     Ok(folded_text)
 }
 
@@ -168,7 +267,8 @@ pub async fn gather_google_articles(search_query: &str) -> Result<Vec<NewsArticl
         remove_script_tags: true,
         remove_a_tags: false,
         remove_img_tags: true,
-        remove_source_tags: false
+        remove_source_tags: false,
+        use_readability: true
     };
 
     let parsed_text = clean_html(&elements, &config, true).await;
@@ -185,6 +285,120 @@ pub async fn gather_google_articles(search_query: &str) -> Result<Vec<NewsArticl
     Ok(articles)
 }
 
+/// # Purpose
+/// Fans a search query out across several news engines concurrently and
+/// merges the results into one deduplicated vector.
+///
+/// # Parameters
+/// * `query` - The search query to run against every selected engine
+/// * `engines` - The engines to search, e.g. `&[Engine::GoogleNews, Engine::BingNews]`
+///
+/// # Returns
+/// * `Vec<NewsArticle>` - The deduplicated articles found across all engines.
+///   Engines that error out are dropped rather than failing the whole call.
+pub async fn gather_articles(query: &str, engines: &[Engine]) -> Vec<NewsArticle> {
+    let mut tasks = FuturesUnordered::new();
+
+    for engine in engines {
+        let engine = *engine;
+        let query = query.to_string();
+        tasks.push(async move { engine.search(&query).await });
+    }
+
+    let mut articles = Vec::new();
+    while let Some(result) = tasks.next().await {
+        match result {
+            Ok(found) => articles.extend(found),
+            Err(err) => eprintln!("Engine search failed, dropping: {:?}", err),
+        }
+    }
+
+    dedupe_articles(articles)
+}
+
+/// # Purpose
+/// Deduplicates articles by normalized URL and near-identical headline so the
+/// same story surfaced by two engines collapses into one entry.
+///
+/// # Parameters
+/// * `articles` - The articles collected from all engines
+///
+/// # Returns
+/// * `Vec<NewsArticle>` - The articles with duplicates removed
+fn dedupe_articles(articles: Vec<NewsArticle>) -> Vec<NewsArticle> {
+    let mut seen_urls = std::collections::HashSet::new();
+    let mut seen_headlines: Vec<String> = Vec::new();
+    let mut deduped = Vec::new();
+
+    for article in articles {
+        let normalized_url = normalize_url(&article.url);
+        let normalized_headline = normalize_headline(&article.headline);
+
+        if seen_urls.contains(&normalized_url) {
+            continue;
+        }
+
+        if seen_headlines
+            .iter()
+            .any(|existing| headline_similarity(existing, &normalized_headline) > 0.9)
+        {
+            continue;
+        }
+
+        seen_urls.insert(normalized_url);
+        seen_headlines.push(normalized_headline);
+        deduped.push(article);
+    }
+
+    deduped
+}
+
+/// # Purpose
+/// Normalizes a URL so trivially different forms of the same link (trailing
+/// slash, query string, casing) compare equal.
+fn normalize_url(url: &str) -> String {
+    url.split(&['?', '#'][..])
+        .next()
+        .unwrap_or(url)
+        .trim_end_matches('/')
+        .to_lowercase()
+}
+
+/// # Purpose
+/// Normalizes a headline down to lowercase, whitespace-collapsed word tokens
+/// so punctuation and casing don't defeat similarity comparison.
+fn normalize_headline(headline: &str) -> String {
+    headline
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || c.is_whitespace())
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// # Purpose
+/// A cheap token-overlap similarity score used to catch near-identical
+/// headlines (e.g. the same story phrased slightly differently by another
+/// engine).
+///
+/// # Returns
+/// * `f64` - The Jaccard similarity of the two headlines' word sets, `0.0..=1.0`
+fn headline_similarity(a: &str, b: &str) -> f64 {
+    let a_tokens: std::collections::HashSet<&str> = a.split_whitespace().collect();
+    let b_tokens: std::collections::HashSet<&str> = b.split_whitespace().collect();
+
+    if a_tokens.is_empty() || b_tokens.is_empty() {
+        return 0.0;
+    }
+
+    let intersection = a_tokens.intersection(&b_tokens).count() as f64;
+    let union = a_tokens.union(&b_tokens).count() as f64;
+
+    intersection / union
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -217,12 +431,88 @@ mod tests {
 
         println!("Last vector: {:?}", article);
 
-        assert!(article.headline.len() > 0 && article.url.len() > 0);
+        assert_eq!(
+            article.url,
+            "https://www.wired.com/story/how-to-use-google-gemini-ai-bard-chatbot/"
+        );
+        assert!(article.headline.len() > 0);
+    }
+
+    /// # Purpose
+    /// Parses a single Bing/DuckDuckGo-style markdown result, which has no
+    /// `#### ... ####` headline markup - the link text is the headline.
+    ///
+    /// # Expects
+    /// A NewsArticle whose headline is the link text, not the raw markdown
+    #[test]
+    fn extract_link_headline_uses_link_text_as_headline() {
+        let example = "[Rates held steady by central bank](https://example.com/news/rates)\n\n2 hours ago";
+
+        let article = extract_link_headline(example).unwrap();
+
+        assert_eq!(article.headline, "Rates held steady by central bank");
+        assert_eq!(article.url, "https://example.com/news/rates");
+    }
+
+    /// # Purpose
+    /// Confirms a link with empty anchor text (e.g. an image link) is
+    /// skipped in favor of the next link that actually has a headline.
+    #[test]
+    fn extract_link_headline_skips_empty_anchor_text() {
+        let example = "[](https://example.com/thumb.jpg)\n\n[Markets rally on rate cut hopes](https://example.com/news/markets)";
+
+        let article = extract_link_headline(example).unwrap();
+
+        assert_eq!(article.headline, "Markets rally on rate cut hopes");
+        assert_eq!(article.url, "https://example.com/news/markets");
+    }
+
+    /// # Purpose
+    /// Articles seen from two different engines with the same URL (modulo
+    /// a trailing slash/query string) should collapse into one entry.
+    #[test]
+    fn dedupe_articles_drops_same_url_from_different_engines() {
+        let articles = vec![
+            NewsArticle {
+                url: "https://example.com/story".to_string(),
+                headline: "Original headline".to_string(),
+            },
+            NewsArticle {
+                url: "https://example.com/story/?utm_source=bing".to_string(),
+                headline: "Original headline, syndicated".to_string(),
+            },
+        ];
+
+        let deduped = dedupe_articles(articles);
+
+        assert_eq!(deduped.len(), 1);
+    }
+
+    /// # Purpose
+    /// Two near-identical headlines for different URLs (the same story
+    /// reworded by another engine) should also collapse via the headline
+    /// similarity check, not just the URL check.
+    #[test]
+    fn dedupe_articles_drops_near_identical_headlines() {
+        let articles = vec![
+            NewsArticle {
+                url: "https://example.com/a".to_string(),
+                headline: "Fed holds interest rates steady".to_string(),
+            },
+            NewsArticle {
+                url: "https://example.org/b".to_string(),
+                headline: "Fed holds interest rates steady!".to_string(),
+            },
+        ];
+
+        let deduped = dedupe_articles(articles);
+
+        assert_eq!(deduped.len(), 1);
     }
 
     /// # Purpose
     /// Retrieves the article text from a URL
-    /// 
+    ///
     /// # Expects
     /// A string of text
     #[tokio::test]
@@ -233,10 +523,13 @@ mod tests {
             remove_script_tags: true,
             remove_a_tags: true,
             remove_img_tags: true,
-            remove_source_tags: true
+            remove_source_tags: true,
+            use_readability: false
         };
 
-        let article = gather_article(&url, &config).await.unwrap();
+        let cache = Cache::new(std::env::temp_dir().join("article_nom_cache"), std::time::Duration::from_secs(3600)).unwrap();
+
+        let article = gather_article(&url, &config, &cache).await.unwrap();
 
         println!("Article: {}", article);
 
@@ -0,0 +1,171 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::errors::gather_error::GatherError;
+use crate::models::html_cleaner::CleanerConfig;
+
+/// # Purpose
+/// An on-disk cache of cleaned article markdown, keyed by a hash of the
+/// source URL plus the `CleanerConfig` used to produce it, so repeated
+/// gathers of the same URL can skip re-scraping entirely.
+pub struct Cache {
+    pub dir: PathBuf,
+    pub max_age: Duration,
+}
+
+impl Cache {
+    /// # Purpose
+    /// Opens (creating if needed) an on-disk cache rooted at `dir`.
+    pub fn new(dir: PathBuf, max_age: Duration) -> Result<Self, GatherError> {
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir, max_age })
+    }
+
+    /// # Purpose
+    /// Looks up the cached markdown for `url`/`config`, returning it only if
+    /// the entry is younger than `max_age`.
+    ///
+    /// # Returns
+    /// * `Option<String>` - The cached markdown, or `None` on a miss or expiry
+    pub fn get(&self, url: &str, config: &CleanerConfig) -> Option<String> {
+        let contents = fs::read_to_string(self.entry_path(url, config)).ok()?;
+        let (timestamp, markdown) = contents.split_once('\n')?;
+        let age_secs = now_secs().checked_sub(timestamp.parse().ok()?)?;
+
+        if age_secs < self.max_age.as_secs() {
+            Some(markdown.to_string())
+        } else {
+            None
+        }
+    }
+
+    /// # Purpose
+    /// Stores `markdown` for `url`/`config`, stamped with the current time.
+    pub fn put(&self, url: &str, config: &CleanerConfig, markdown: &str) -> Result<(), GatherError> {
+        let contents = format!("{}\n{}", now_secs(), markdown);
+        fs::write(self.entry_path(url, config), contents)?;
+        Ok(())
+    }
+
+    /// # Purpose
+    /// Deletes every cache entry older than `max_age`.
+    pub fn evict_expired(&self) -> Result<(), GatherError> {
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+
+            let is_expired = fs::read_to_string(&path)
+                .ok()
+                .and_then(|contents| contents.split_once('\n').map(|(ts, _)| ts.to_string()))
+                .and_then(|ts| ts.parse::<u64>().ok())
+                .map(|ts| now_secs().saturating_sub(ts) >= self.max_age.as_secs())
+                .unwrap_or(false);
+
+            if is_expired {
+                fs::remove_file(&path)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn entry_path(&self, url: &str, config: &CleanerConfig) -> PathBuf {
+        self.dir.join(format!("{:016x}.cache", cache_key(url, config)))
+    }
+}
+
+/// # Purpose
+/// Hashes a URL together with the cleaner config that would be applied to
+/// it, so the same URL cleaned two different ways caches separately.
+fn cache_key(url: &str, config: &CleanerConfig) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    url.hash(&mut hasher);
+    config.remove_script_tags.hash(&mut hasher);
+    config.remove_a_tags.hash(&mut hasher);
+    config.remove_img_tags.hash(&mut hasher);
+    config.remove_source_tags.hash(&mut hasher);
+    config.use_readability.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// # Purpose
+/// The current Unix timestamp, in seconds, used to stamp and age cache entries.
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> CleanerConfig {
+        CleanerConfig {
+            remove_script_tags: true,
+            remove_a_tags: true,
+            remove_img_tags: true,
+            remove_source_tags: true,
+            use_readability: false,
+        }
+    }
+
+    fn test_cache(max_age: Duration) -> Cache {
+        let dir = std::env::temp_dir().join(format!("article_nom_cache_test_{:016x}", cache_key("seed", &test_config())));
+        Cache::new(dir, max_age).unwrap()
+    }
+
+    /// # Purpose
+    /// A freshly-written entry should be served back before `max_age` elapses.
+    #[test]
+    fn get_returns_fresh_entry() {
+        let cache = test_cache(Duration::from_secs(3600));
+        let config = test_config();
+
+        cache.put("https://example.com/a", &config, "cached markdown").unwrap();
+
+        assert_eq!(
+            cache.get("https://example.com/a", &config),
+            Some("cached markdown".to_string())
+        );
+    }
+
+    /// # Purpose
+    /// An entry older than `max_age` is a miss, not stale data.
+    #[test]
+    fn get_returns_none_for_expired_entry() {
+        let cache = test_cache(Duration::from_secs(0));
+        let config = test_config();
+
+        cache.put("https://example.com/b", &config, "cached markdown").unwrap();
+
+        assert_eq!(cache.get("https://example.com/b", &config), None);
+    }
+
+    /// # Purpose
+    /// `evict_expired` should remove the on-disk entry for an expired URL so
+    /// it no longer even counts as a stale hit, without touching entries
+    /// still within `max_age`.
+    #[test]
+    fn evict_expired_removes_only_expired_entries() {
+        let cache = test_cache(Duration::from_secs(3600));
+        let config = test_config();
+
+        cache.put("https://example.com/c", &config, "stale").unwrap();
+        let entry_path = cache.entry_path("https://example.com/c", &config);
+        assert!(entry_path.exists());
+        // Backdate the entry to twice `max_age` so it is expired.
+        fs::write(&entry_path, format!("{}\nstale", now_secs() - 7200)).unwrap();
+
+        cache.put("https://example.com/d", &config, "fresh").unwrap();
+        let fresh_path = cache.entry_path("https://example.com/d", &config);
+
+        cache.evict_expired().unwrap();
+
+        assert!(!entry_path.exists());
+        assert!(fresh_path.exists());
+    }
+}
@@ -0,0 +1,211 @@
+use std::fs::File;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use epub_builder::{EpubBuilder, EpubContent, ReferenceType, ZipLibrary};
+use regex::Regex;
+
+use crate::errors::gather_error::GatherError;
+use crate::models::news_article::NewsArticle;
+
+/// # Purpose
+/// Builds a single EPUB archive out of gathered articles, one chapter per
+/// article, so a batch of stories can be archived for offline reading.
+///
+/// # Parameters
+/// * `articles` - The articles paired with their cleaned markdown bodies,
+///   in the order they should appear as chapters
+/// * `out_path` - Where the `.epub` file should be written
+///
+/// # Returns
+/// * `Result<(), GatherError>` - `Ok(())` once the EPUB has been written to disk
+pub fn export_epub(articles: &[(NewsArticle, String)], out_path: &Path) -> Result<(), GatherError> {
+    let mut builder = EpubBuilder::new(ZipLibrary::new()?)?;
+
+    builder.metadata("title", "Gathered Articles")?;
+    builder.metadata("author", "unknown")?;
+    builder.metadata("generated", generated_date())?;
+
+    for (index, (article, markdown)) in articles.iter().enumerate() {
+        let chapter_path = format!("chapter_{}.xhtml", index);
+        let xhtml = markdown_to_xhtml(&article.headline, markdown);
+
+        builder.add_content(
+            EpubContent::new(chapter_path, xhtml.as_bytes())
+                .title(article.headline.clone())
+                .reftype(ReferenceType::Text),
+        )?;
+    }
+
+    let file = File::create(out_path)?;
+    builder.generate(file)?;
+
+    Ok(())
+}
+
+/// # Purpose
+/// Converts a single article's markdown body into minimal XHTML suitable for
+/// an EPUB chapter.
+///
+/// # Parameters
+/// * `headline` - The article's headline, used as the chapter title
+/// * `markdown` - The cleaned markdown body produced by `gather_article`
+///
+/// # Returns
+/// * `String` - A standalone XHTML document for the chapter
+fn markdown_to_xhtml(headline: &str, markdown: &str) -> String {
+    let body = markdown
+        .split("\n\n")
+        .map(str::trim)
+        .filter(|paragraph| !paragraph.is_empty())
+        .map(paragraph_to_xhtml)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<html xmlns=\"http://www.w3.org/1999/xhtml\"><head><title>{title}</title></head><body><h1>{title}</h1>\n{body}</body></html>",
+        title = escape_xml(headline),
+        body = body
+    )
+}
+
+/// # Purpose
+/// Converts a single markdown paragraph into its XHTML block: `# `..`###### `
+/// headings (including Google News' `#### heading ####` double-sided form)
+/// become `<h1>`-`<h6>`, everything else becomes a `<p>` with inline markdown
+/// (bold, italic, links) converted.
+///
+/// # Parameters
+/// * `paragraph` - A single trimmed, non-empty markdown paragraph
+///
+/// # Returns
+/// * `String` - The paragraph's XHTML block element
+fn paragraph_to_xhtml(paragraph: &str) -> String {
+    let heading_regex = Regex::new(r"^(#{1,6})\s+(.*?)\s*#*$").unwrap();
+
+    match heading_regex.captures(paragraph) {
+        Some(cap) => {
+            let level = cap[1].len();
+            format!("<h{level}>{}</h{level}>", inline_to_xhtml(&cap[2]))
+        }
+        None => format!("<p>{}</p>", inline_to_xhtml(paragraph)),
+    }
+}
+
+/// # Purpose
+/// Converts markdown inline formatting (links, bold, italic) within a single
+/// block into XHTML, escaping everything else.
+///
+/// # Parameters
+/// * `text` - The markdown text to convert, with no surrounding block markup
+///
+/// # Returns
+/// * `String` - The text as XHTML-safe inline markup
+fn inline_to_xhtml(text: &str) -> String {
+    let link_regex = Regex::new(r"\[([^\]]*)\]\(([^)]*)\)").unwrap();
+    let bold_regex = Regex::new(r"\*\*([^*]+)\*\*").unwrap();
+    let italic_regex = Regex::new(r"\*([^*]+)\*").unwrap();
+
+    let escaped = escape_xml(text);
+    let linked = link_regex.replace_all(&escaped, "<a href=\"$2\">$1</a>");
+    let bolded = bold_regex.replace_all(&linked, "<strong>$1</strong>");
+    let italicized = italic_regex.replace_all(&bolded, "<em>$1</em>");
+
+    italicized.into_owned()
+}
+
+/// # Purpose
+/// Escapes the characters XHTML treats as markup so article text embeds
+/// safely into a chapter document, including as an attribute value (e.g. a
+/// scraped URL interpolated into `href="..."`).
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// # Purpose
+/// Formats the current time as a Unix timestamp string for the EPUB's
+/// "generated" metadata field.
+fn generated_date() -> String {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs().to_string())
+        .unwrap_or_else(|_| "0".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// # Purpose
+    /// A markdown header paragraph should become its matching `<hN>` tag,
+    /// not survive as literal `#` characters inside a `<p>`.
+    #[test]
+    fn paragraph_to_xhtml_converts_headers() {
+        assert_eq!(
+            paragraph_to_xhtml("## Market Update"),
+            "<h2>Market Update</h2>"
+        );
+    }
+
+    /// # Purpose
+    /// Google News' double-sided `#### heading ####` markup should also
+    /// resolve to a single `<hN>` tag with the trailing hashes stripped.
+    #[test]
+    fn paragraph_to_xhtml_converts_double_sided_headers() {
+        assert_eq!(
+            paragraph_to_xhtml("#### Breaking News ####"),
+            "<h4>Breaking News</h4>"
+        );
+    }
+
+    /// # Purpose
+    /// Inline links, bold, and italic markup should convert to their XHTML
+    /// equivalents rather than surviving as literal markdown syntax.
+    #[test]
+    fn inline_to_xhtml_converts_links_bold_and_italic() {
+        let converted = inline_to_xhtml("See [the report](https://example.com/report) for **full** details, *really*.");
+
+        assert_eq!(
+            converted,
+            "See <a href=\"https://example.com/report\">the report</a> for <strong>full</strong> details, <em>really</em>."
+        );
+    }
+
+    /// # Purpose
+    /// Plain text with no markdown and no special characters should pass
+    /// through a regular paragraph unchanged save for the `<p>` wrapper.
+    #[test]
+    fn paragraph_to_xhtml_wraps_plain_text() {
+        assert_eq!(
+            paragraph_to_xhtml("Just a regular sentence."),
+            "<p>Just a regular sentence.</p>"
+        );
+    }
+
+    /// # Purpose
+    /// Characters XHTML treats as markup must still be escaped even once
+    /// they've passed through the inline markdown conversion.
+    #[test]
+    fn inline_to_xhtml_escapes_special_characters() {
+        assert_eq!(
+            inline_to_xhtml("Rates < expectations & above > forecasts"),
+            "Rates &lt; expectations &amp; above &gt; forecasts"
+        );
+    }
+
+    /// # Purpose
+    /// A link whose URL contains a literal `"` must not break out of the
+    /// `href="..."` attribute it gets interpolated into.
+    #[test]
+    fn inline_to_xhtml_escapes_quotes_in_link_urls() {
+        let converted = inline_to_xhtml("[report](https://example.com/r?q=\"x\")");
+
+        assert_eq!(
+            converted,
+            "<a href=\"https://example.com/r?q=&quot;x&quot;\">report</a>"
+        );
+    }
+}
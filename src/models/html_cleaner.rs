@@ -1,18 +1,24 @@
 use regex::Regex;
 
+use crate::models::readability::Readability;
+
 #[derive(Clone)]
 pub struct CleanerConfig {
     pub remove_script_tags: bool,
     pub remove_a_tags: bool,
     pub remove_img_tags: bool,
-    pub remove_source_tags: bool
+    pub remove_source_tags: bool,
+    /// Run the DOM-based Readability scorer to isolate the main article body
+    /// instead of (only) stripping tag types with regexes.
+    pub use_readability: bool
 }
 
 pub struct HtmlCleaner {
     remove_script_tags: bool,
     remove_a_tags: bool,
     remove_img_tags: bool,
-    remove_source_tags: bool
+    remove_source_tags: bool,
+    use_readability: bool
 }
 
 impl HtmlCleaner {
@@ -21,7 +27,8 @@ impl HtmlCleaner {
             remove_script_tags: false,
             remove_a_tags: false,
             remove_img_tags: false,
-            remove_source_tags: false
+            remove_source_tags: false,
+            use_readability: false
         }
     }
 
@@ -30,12 +37,17 @@ impl HtmlCleaner {
         self.remove_a_tags = config.remove_a_tags;
         self.remove_img_tags = config.remove_img_tags;
         self.remove_source_tags = config.remove_source_tags;
+        self.use_readability = config.use_readability;
 
         self
     }
 
     pub fn clean(&self, input: &str) -> String {
-        let mut clean_text = input.to_string();
+        let mut clean_text = if self.use_readability {
+            Readability::extract(input)
+        } else {
+            input.to_string()
+        };
 
         if self.remove_script_tags {
             let script_tag_pattern = r"(?is)<script.*?</script>";
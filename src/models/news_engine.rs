@@ -0,0 +1,99 @@
+use crate::errors::gather_error::GatherError;
+use crate::models::html_cleaner::CleanerConfig;
+use crate::models::news_article::NewsArticle;
+use crate::models::news_scraper::NewsScraper;
+
+/// # Purpose
+/// The news search engines `gather_articles` can fan a query out across.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Engine {
+    GoogleNews,
+    BingNews,
+    DuckDuckGo,
+}
+
+/// # Purpose
+/// A pluggable news search backend that can build its own search URL and
+/// scrape + parse its own results into `NewsArticle`s.
+#[async_trait::async_trait]
+pub trait NewsEngine {
+    /// # Purpose
+    /// Builds the search URL for this engine given a raw query.
+    fn url(&self, query: &str) -> String;
+
+    /// # Purpose
+    /// Scrapes and parses this engine's search results for `query`.
+    async fn search(&self, query: &str) -> Result<Vec<NewsArticle>, GatherError>;
+}
+
+#[async_trait::async_trait]
+impl NewsEngine for Engine {
+    fn url(&self, query: &str) -> String {
+        match self {
+            Engine::GoogleNews => format!(
+                "https://news.google.com/search?q={}&hl=en-US&gl=US&ceid=US%3Aen",
+                query
+            ),
+            Engine::BingNews => format!("https://www.bing.com/news/search?q={}", query),
+            Engine::DuckDuckGo => format!("https://duckduckgo.com/html/?q={}+news", query),
+        }
+    }
+
+    async fn search(&self, query: &str) -> Result<Vec<NewsArticle>, GatherError> {
+        let url = self.url(query);
+
+        let mut scraper = NewsScraper::new().await?;
+        let elements = scraper
+            .get_elements_with_selector(&url, self.result_selector())
+            .await?;
+        let _ = scraper.close().await;
+
+        let config = CleanerConfig {
+            remove_script_tags: true,
+            remove_a_tags: false,
+            remove_img_tags: true,
+            remove_source_tags: false,
+            use_readability: false,
+        };
+
+        let parsed_text = crate::clean_html(&elements, &config, true).await;
+
+        let mut articles = Vec::new();
+        for text in parsed_text {
+            if let Some(article) = self.parse_result(&text).await {
+                articles.push(article);
+            }
+        }
+
+        Ok(articles)
+    }
+}
+
+impl Engine {
+    /// # Purpose
+    /// The CSS selector that isolates a single search result on this
+    /// engine's results page. `GoogleNews` wraps each story in its own
+    /// `<article>`; Bing and DuckDuckGo use their own result card markup, so
+    /// reusing `article` for them would fall through to scraping the whole
+    /// `body` as one blob.
+    fn result_selector(&self) -> &'static str {
+        match self {
+            Engine::GoogleNews => "article",
+            Engine::BingNews => "div.news-card",
+            Engine::DuckDuckGo => "div.result",
+        }
+    }
+
+    /// # Purpose
+    /// Parses one already-isolated search result (already reduced to
+    /// markdown for a single `result_selector` match) into a `NewsArticle`.
+    /// `GoogleNews` keeps its `#### headline ####` + redirect-token parsing;
+    /// the other engines don't emit that markup, so their headline is just
+    /// the text of the result's link.
+    async fn parse_result(&self, text: &str) -> Option<NewsArticle> {
+        match self {
+            Engine::GoogleNews => crate::extract_url_headline(text).await,
+            Engine::BingNews | Engine::DuckDuckGo => crate::extract_link_headline(text),
+        }
+    }
+}
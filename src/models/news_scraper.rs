@@ -1,20 +1,112 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
 use fantoccini::{ClientBuilder, Locator};
 use futures::{stream, StreamExt};
+use reqwest::Client;
+use tokio::sync::{Mutex, Semaphore};
 
 use crate::errors::gather_error::GatherError;
+use crate::models::readability::Readability;
+
+/// A small rotating pool of realistic desktop browser user agents, cycled
+/// through on each HTTP-backend request to avoid trivial blocking.
+const USER_AGENTS: &[&str] = &[
+    "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/124.0.0.0 Safari/537.36",
+    "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.4 Safari/605.1.15",
+    "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/124.0.0.0 Safari/537.36",
+    "Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:125.0) Gecko/20100101 Firefox/125.0",
+];
+
+static HTTP_CLIENT: OnceLock<Client> = OnceLock::new();
+
+/// # Purpose
+/// Returns the crate-wide pooled `reqwest::Client`, built once on first use.
+fn http_client() -> &'static Client {
+    HTTP_CLIENT.get_or_init(|| {
+        Client::builder()
+            .redirect(reqwest::redirect::Policy::limited(10))
+            .build()
+            .expect("failed to build the shared reqwest client")
+    })
+}
+
+/// # Purpose
+/// Picks the next user agent out of `USER_AGENTS`, rotating on every call.
+fn rotating_user_agent() -> &'static str {
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+
+    let index = NEXT.fetch_add(1, Ordering::Relaxed) % USER_AGENTS.len();
+    USER_AGENTS[index]
+}
+
+/// # Purpose
+/// Selects how `NewsScraper` fetches a page.
+pub enum Backend {
+    /// A full WebDriver session (geckodriver on `localhost:4444`), needed for
+    /// pages that only render their content via JavaScript.
+    WebDriver,
+    /// A plain pooled HTTP GET, for static pages that don't need JS rendering.
+    Http,
+}
+
+/// # Purpose
+/// Tunes how politely and how patiently `NewsScraper` fetches pages.
+#[derive(Clone)]
+pub struct ScraperConfig {
+    /// How long to wait for a single fetch before giving up with `GatherError::Timeout`.
+    pub request_timeout: Duration,
+    /// The maximum number of fetches allowed to run at once, process-wide.
+    pub max_concurrent: usize,
+    /// The minimum time to leave between two requests to the same host.
+    pub per_host_delay: Duration,
+}
+
+impl Default for ScraperConfig {
+    fn default() -> Self {
+        Self {
+            request_timeout: Duration::from_secs(30),
+            max_concurrent: 4,
+            per_host_delay: Duration::from_millis(500),
+        }
+    }
+}
 
 pub struct NewsScraper {
+    backend: Backend,
     client: Option<fantoccini::Client>,
+    config: ScraperConfig,
 }
 
 impl NewsScraper {
+    /// Defaults to the `WebDriver` backend, matching this crate's prior behavior.
     pub async fn new() -> Result<Self, fantoccini::error::NewSessionError> {
-        let client = ClientBuilder::native()
-            .connect("http://localhost:4444")
-            .await?;
+        Self::with_backend(Backend::WebDriver).await
+    }
+
+    pub async fn with_backend(backend: Backend) -> Result<Self, fantoccini::error::NewSessionError> {
+        Self::with_config(backend, ScraperConfig::default()).await
+    }
+
+    pub async fn with_config(
+        backend: Backend,
+        config: ScraperConfig,
+    ) -> Result<Self, fantoccini::error::NewSessionError> {
+        let client = match backend {
+            Backend::WebDriver => Some(
+                ClientBuilder::native()
+                    .connect("http://localhost:4444")
+                    .await?,
+            ),
+            Backend::Http => None,
+        };
 
         Ok(Self {
-            client: Some(client),
+            backend,
+            client,
+            config,
         })
     }
 
@@ -27,19 +119,85 @@ impl NewsScraper {
     }
 
     /// # Purpose
-    /// This function is used for gathering article data.
-    /// Using Selenium it gathers the HTML content of all articles, or body elements if no articles are found.
+    /// This function is used for gathering article data, using whichever
+    /// backend the scraper was built with. Bounds the number of fetches
+    /// running at once, waits out a minimum per-host delay, and times out
+    /// if the fetch takes too long.
     ///
     /// # Parameters
     /// * `url` - The URL of the article to be parsed
-    /// * `clean_links` - Whether or not to remove links from the article
     ///
     /// # Returns
-    /// * `String` - The article text in Markdown
+    /// * `Vec<String>` - The HTML content of the article's elements
+    pub async fn get_elements(&self, url: &str) -> Result<Vec<String>, GatherError> {
+        self.get_elements_with_selector(url, "article").await
+    }
+
+    /// # Purpose
+    /// Same as [`NewsScraper::get_elements`], but lets the caller pick which
+    /// CSS selector identifies a single result on the page (e.g. a search
+    /// engine's per-result card) instead of assuming `article`.
+    ///
+    /// # Parameters
+    /// * `url` - The URL of the page to be parsed
+    /// * `selector` - The CSS selector for one result/article on the page.
+    ///   Only used by the `WebDriver` backend; falls back to `body` if no
+    ///   elements match.
+    ///
+    /// # Returns
+    /// * `Vec<String>` - The HTML content of the matched elements
+    pub async fn get_elements_with_selector(
+        &self,
+        url: &str,
+        selector: &str,
+    ) -> Result<Vec<String>, GatherError> {
+        let _permit = concurrency_semaphore(self.config.max_concurrent)
+            .acquire()
+            .await
+            .expect("concurrency semaphore should never be closed");
+
+        wait_for_host_delay(url, self.config.per_host_delay).await;
+
+        let fetch = async {
+            match self.backend {
+                Backend::Http => self.get_elements_http(url).await,
+                Backend::WebDriver => self.get_elements_webdriver(url, selector).await,
+            }
+        };
+
+        match tokio::time::timeout(self.config.request_timeout, fetch).await {
+            Ok(result) => result,
+            Err(_) => Err(GatherError::Timeout()),
+        }
+    }
+
+    /// # Purpose
+    /// Fetches `url` with the pooled HTTP client, rotating the `User-Agent`
+    /// header, and runs the fetched HTML through the Readability extractor
+    /// since there is no DOM to run CSS selectors against.
+    async fn get_elements_http(&self, url: &str) -> Result<Vec<String>, GatherError> {
+        let response = http_client()
+            .get(url)
+            .header(reqwest::header::USER_AGENT, rotating_user_agent())
+            .send()
+            .await?;
+
+        let html = response.text().await?;
+
+        Ok(vec![Readability::extract(&html)])
+    }
+
+    /// # Purpose
+    /// Using Selenium, gathers the HTML content of all elements matching
+    /// `selector`, or `body` elements if none are found.
     ///
     /// # Notes
     /// Geckodriver must be running on port 4444 for this function to work.
-    pub async fn get_elements(&self, url: &str) -> Result<Vec<String>, GatherError> {
+    async fn get_elements_webdriver(
+        &self,
+        url: &str,
+        selector: &str,
+    ) -> Result<Vec<String>, GatherError> {
         let client = match self.client.as_ref() {
             Some(client) => client,
             None => return Err(GatherError::SessionDropped()),
@@ -52,10 +210,10 @@ impl NewsScraper {
             eprintln!("Warning: URL redirect from {} to {}", url, current_url);
         }
 
-        let elements = match client.find_all(Locator::Css("article")).await {
-            Ok(articles) if !articles.is_empty() => articles,
+        let elements = match client.find_all(Locator::Css(selector)).await {
+            Ok(found) if !found.is_empty() => found,
             _ => {
-                println!("Article not found, using body");
+                println!("No '{}' elements found, using body", selector);
                 client.find_all(Locator::Css("body")).await?
             }
         };
@@ -72,3 +230,133 @@ impl NewsScraper {
         Ok(html_contents)
     }
 }
+
+/// # Purpose
+/// Returns the process-wide semaphore that bounds how many fetches (across
+/// every `NewsScraper`, including ones spawned by the aggregator) run at
+/// once for a given `max_concurrent` bound, creating it the first time that
+/// bound is requested. Keyed by `max_concurrent` rather than a single
+/// `OnceLock<Semaphore>` so that scrapers configured with different
+/// `max_concurrent` values each get a correctly-sized pool instead of all
+/// silently sharing whichever size happened to initialize the lock first.
+fn concurrency_semaphore(max_concurrent: usize) -> &'static Semaphore {
+    static SEMAPHORES: OnceLock<std::sync::Mutex<HashMap<usize, &'static Semaphore>>> =
+        OnceLock::new();
+    let semaphores = SEMAPHORES.get_or_init(|| std::sync::Mutex::new(HashMap::new()));
+
+    let mut semaphores = semaphores
+        .lock()
+        .expect("concurrency semaphore registry mutex should never be poisoned");
+
+    *semaphores
+        .entry(max_concurrent)
+        .or_insert_with(|| Box::leak(Box::new(Semaphore::new(max_concurrent))))
+}
+
+/// # Purpose
+/// Sleeps, if necessary, so that this request leaves at least `delay` since
+/// the last request made to the same host. Tracks the next instant each host
+/// is allowed to be hit next, rather than the last request's timestamp, so
+/// several calls for the same host queuing up faster than the lock+sleep
+/// cycle stack up one `delay` apart instead of collapsing onto one slot.
+async fn wait_for_host_delay(url: &str, delay: Duration) {
+    static NEXT_ALLOWED: OnceLock<Mutex<HashMap<String, Instant>>> = OnceLock::new();
+    let next_allowed = NEXT_ALLOWED.get_or_init(|| Mutex::new(HashMap::new()));
+
+    let host = reqwest::Url::parse(url)
+        .ok()
+        .and_then(|parsed| parsed.host_str().map(str::to_string))
+        .unwrap_or_default();
+
+    let wait = {
+        let mut hosts = next_allowed.lock().await;
+        let now = Instant::now();
+        let wait = hosts
+            .get(&host)
+            .map(|next| next.saturating_duration_since(now))
+            .unwrap_or(Duration::ZERO);
+
+        hosts.insert(host, now + wait + delay);
+        wait
+    };
+
+    if !wait.is_zero() {
+        tokio::time::sleep(wait).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// # Purpose
+    /// Calling `rotating_user_agent` enough times to wrap around the pool
+    /// should cycle through every entry rather than always returning the
+    /// first (or a random) one.
+    #[test]
+    fn rotating_user_agent_cycles_through_every_entry() {
+        let seen: std::collections::HashSet<&str> = (0..USER_AGENTS.len())
+            .map(|_| rotating_user_agent())
+            .collect();
+
+        assert_eq!(seen.len(), USER_AGENTS.len());
+    }
+
+    /// # Purpose
+    /// A second request to the same host within `delay` of the first should
+    /// actually wait; a request to a different host should not be throttled
+    /// by the first host's timer.
+    #[tokio::test]
+    async fn wait_for_host_delay_throttles_same_host_only() {
+        let delay = Duration::from_millis(100);
+
+        let start = Instant::now();
+        wait_for_host_delay("https://rotating-user-agent-test-host.example/a", delay).await;
+        wait_for_host_delay("https://rotating-user-agent-test-host.example/b", delay).await;
+        assert!(start.elapsed() >= delay);
+
+        let start = Instant::now();
+        wait_for_host_delay("https://another-test-host.example/a", delay).await;
+        assert!(start.elapsed() < delay);
+    }
+
+    /// # Purpose
+    /// Several near-simultaneous requests to the same host (e.g. a fan-out
+    /// over many URLs on one host) should stack up one `delay` apart instead
+    /// of collapsing onto a single reserved slot.
+    #[tokio::test]
+    async fn wait_for_host_delay_stacks_concurrent_requests() {
+        let delay = Duration::from_millis(100);
+        let host = "https://stacking-test-host.example/page";
+        let start = Instant::now();
+
+        let calls = (0..4).map(|_| async move {
+            wait_for_host_delay(host, delay).await;
+            start.elapsed()
+        });
+
+        let elapsed: Vec<Duration> = futures::future::join_all(calls).await;
+
+        assert!(elapsed[3] >= delay * 3);
+    }
+
+    /// # Purpose
+    /// Two different `max_concurrent` values must not share a semaphore -
+    /// each size gets its own pool, sized correctly, rather than whichever
+    /// size happened to request a semaphore first winning for the rest of
+    /// the process.
+    #[test]
+    fn concurrency_semaphore_is_sized_per_max_concurrent() {
+        let two = concurrency_semaphore(2);
+        let five = concurrency_semaphore(5);
+
+        assert_eq!(two.available_permits(), 2);
+        assert_eq!(five.available_permits(), 5);
+
+        // Requesting the same size again returns the same pool, not a fresh one.
+        assert_eq!(
+            concurrency_semaphore(2).available_permits(),
+            two.available_permits()
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use scraper::{ElementRef, Html, Selector};
+use std::collections::HashMap;
+
+const POSITIVE_CLASS_ID_WORDS: &[&str] = &["article", "content", "body"];
+const NEGATIVE_CLASS_ID_WORDS: &[&str] = &["comment", "sidebar", "footer", "nav"];
+
+const MIN_CANDIDATE_TEXT_LEN: usize = 25;
+const SIBLING_SCORE_THRESHOLD_RATIO: f64 = 0.2;
+
+/// # Purpose
+/// A Readability-style content extractor that scores DOM nodes to find the
+/// main article body of an HTML document, similar to Mozilla's Readability.js.
+pub struct Readability;
+
+impl Readability {
+    /// # Purpose
+    /// Extracts the main article content from a raw HTML document by scoring
+    /// `<p>`, `<td>`, and `<pre>` nodes and walking up to find the best
+    /// containing ancestor.
+    ///
+    /// # Parameters
+    /// * `html` - The raw HTML to be scored and trimmed down to its article body
+    ///
+    /// # Returns
+    /// * `String` - The inner HTML of the highest-scoring content node followed
+    ///   by any qualifying following siblings, or the original HTML if no
+    ///   candidate could be scored
+    pub fn extract(html: &str) -> String {
+        let document = Html::parse_document(html);
+        let selector = Selector::parse("p, td, pre").unwrap();
+
+        let mut scores: HashMap<ego_tree::NodeId, f64> = HashMap::new();
+
+        for candidate in document.select(&selector) {
+            let text: String = candidate.text().collect();
+            if text.trim().len() < MIN_CANDIDATE_TEXT_LEN {
+                continue;
+            }
+
+            let base_score = 1.0
+                + text.matches(',').count() as f64
+                + ((text.len() / 100) as f64).min(3.0);
+
+            if let Some(parent) = candidate.parent().and_then(ElementRef::wrap) {
+                let entry = scores.entry(parent.id()).or_insert_with(|| Self::class_id_bonus(&parent));
+                *entry += base_score;
+
+                if let Some(grandparent) = parent.parent().and_then(ElementRef::wrap) {
+                    let entry = scores
+                        .entry(grandparent.id())
+                        .or_insert_with(|| Self::class_id_bonus(&grandparent));
+                    *entry += base_score / 2.0;
+                }
+            }
+        }
+
+        let top = scores.into_iter().filter_map(|(id, score)| {
+            let node = document.tree.get(id)?;
+            let element = ElementRef::wrap(node)?;
+            let scaled = score * (1.0 - Self::link_density(&element));
+            Some((element, scaled))
+        });
+
+        let best = top.fold(None, |best: Option<(ElementRef, f64)>, (element, score)| {
+            match &best {
+                Some((_, best_score)) if *best_score >= score => best,
+                _ => Some((element, score)),
+            }
+        });
+
+        match best {
+            Some((root, score)) => Self::article_with_siblings(root, score),
+            None => html.to_string(),
+        }
+    }
+
+    /// # Purpose
+    /// Scores a node's `class`/`id` attributes for keywords commonly used to
+    /// mark up (or exclude) real article content.
+    ///
+    /// # Parameters
+    /// * `element` - The element whose attributes should be inspected
+    ///
+    /// # Returns
+    /// * `f64` - A positive, negative, or zero bonus to seed the node's score
+    fn class_id_bonus(element: &ElementRef) -> f64 {
+        let haystack = format!(
+            "{} {}",
+            element.value().attr("class").unwrap_or_default(),
+            element.value().attr("id").unwrap_or_default()
+        )
+        .to_lowercase();
+
+        let mut bonus = 0.0;
+        for word in POSITIVE_CLASS_ID_WORDS {
+            if haystack.contains(word) {
+                bonus += 25.0;
+            }
+        }
+        for word in NEGATIVE_CLASS_ID_WORDS {
+            if haystack.contains(word) {
+                bonus -= 25.0;
+            }
+        }
+
+        bonus
+    }
+
+    /// # Purpose
+    /// Computes the fraction of a node's text that sits inside `<a>` tags, used
+    /// to penalize link-heavy navigation and boilerplate blocks.
+    ///
+    /// # Parameters
+    /// * `element` - The element whose text and anchor text should be measured
+    ///
+    /// # Returns
+    /// * `f64` - The link density, between `0.0` and `1.0`
+    fn link_density(element: &ElementRef) -> f64 {
+        let total_len: usize = element.text().map(|t| t.len()).sum();
+        if total_len == 0 {
+            return 0.0;
+        }
+
+        let link_selector = Selector::parse("a").unwrap();
+        let link_len: usize = element
+            .select(&link_selector)
+            .flat_map(|a| a.text())
+            .map(|t| t.len())
+            .sum();
+
+        (link_len as f64 / total_len as f64).min(1.0)
+    }
+
+    /// # Purpose
+    /// Builds the final article HTML from the winning candidate node plus any
+    /// sibling nodes that look like a continuation of the article.
+    ///
+    /// # Parameters
+    /// * `root` - The highest-scoring candidate node
+    /// * `root_score` - The root's final (link-density-scaled) score, used to
+    ///   derive the threshold a sibling must clear to be appended
+    ///
+    /// # Returns
+    /// * `String` - The combined inner HTML of the root and qualifying siblings
+    fn article_with_siblings(root: ElementRef, root_score: f64) -> String {
+        let threshold = (root_score * SIBLING_SCORE_THRESHOLD_RATIO).max(10.0);
+        let mut content = root.inner_html();
+
+        for sibling in root.next_siblings().filter_map(ElementRef::wrap) {
+            let text: String = sibling.text().collect();
+            let density = Self::link_density(&sibling);
+
+            let sibling_score = Self::class_id_bonus(&sibling) + text.len() as f64 / 100.0;
+            let looks_like_content = text.trim().len() > 100 && density < 0.25;
+
+            if sibling_score > threshold || looks_like_content {
+                content.push_str(&sibling.html());
+            }
+        }
+
+        content
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// # Purpose
+    /// Given a page with an obvious nav/sidebar and a real article body, the
+    /// extractor should keep the article paragraphs and drop the boilerplate.
+    #[test]
+    fn extract_prefers_article_body_over_nav_and_sidebar() {
+        let html = r#"
+            <html>
+                <body>
+                    <nav><a href="/">Home</a> <a href="/about">About</a> <a href="/contact">Contact</a></nav>
+                    <div class="sidebar"><p>Subscribe to our newsletter for more updates and offers.</p></div>
+                    <article>
+                        <p>This is the opening paragraph of a real news story, long enough to score well.</p>
+                        <p>This is a second paragraph continuing the same story with plenty more detail, commentary.</p>
+                    </article>
+                </body>
+            </html>
+        "#;
+
+        let extracted = Readability::extract(html);
+
+        assert!(extracted.contains("opening paragraph of a real news story"));
+        assert!(!extracted.contains("Subscribe to our newsletter"));
+    }
+
+    /// # Purpose
+    /// A document with no paragraph-like candidates should fall back to
+    /// returning the original HTML untouched, rather than panicking or
+    /// returning an empty string.
+    #[test]
+    fn extract_falls_back_to_original_html_with_no_candidates() {
+        let html = "<html><body><span>too short</span></body></html>";
+
+        assert_eq!(Readability::extract(html), html);
+    }
+}